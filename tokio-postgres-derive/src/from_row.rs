@@ -0,0 +1,157 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Fields, Ident, LitStr};
+
+struct FieldAttr {
+    rename: Option<String>,
+    flatten: bool,
+}
+
+fn parse_field_attr(field: &syn::Field) -> syn::Result<FieldAttr> {
+    let mut attr = FieldAttr {
+        rename: None,
+        flatten: false,
+    };
+
+    for meta in field
+        .attrs
+        .iter()
+        .filter(|a| a.path().is_ident("postgres"))
+    {
+        meta.parse_nested_meta(|nested| {
+            if nested.path.is_ident("rename") {
+                let value = nested.value()?;
+                let s: LitStr = value.parse()?;
+                attr.rename = Some(s.value());
+                Ok(())
+            } else if nested.path.is_ident("flatten") {
+                attr.flatten = true;
+                Ok(())
+            } else {
+                Err(nested.error("unsupported #[postgres(..)] attribute"))
+            }
+        })?;
+    }
+
+    Ok(attr)
+}
+
+pub fn expand_derive_from_row(input: DeriveInput) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+
+    let fields = match &input.data {
+        Data::Struct(s) => match &s.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &input,
+                    "#[derive(FromRow)] only supports structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input,
+                "#[derive(FromRow)] can only be applied to structs",
+            ))
+        }
+    };
+
+    let field_inits = fields
+        .iter()
+        .map(|field| {
+            let field_name = field.ident.as_ref().unwrap();
+            let attr = parse_field_attr(field)?;
+
+            if attr.flatten {
+                Ok(quote! { #field_name: tokio_postgres::row::FromRow::from_row(row)? })
+            } else {
+                let column_name = attr
+                    .rename
+                    .unwrap_or_else(|| field_name.to_string());
+                Ok(quote! { #field_name: row.try_get(#column_name)? })
+            }
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    let ident: &Ident = name;
+    Ok(quote! {
+        impl tokio_postgres::row::FromRow for #ident {
+            fn from_row(row: &tokio_postgres::Row) -> Result<Self, tokio_postgres::Error> {
+                Ok(#ident {
+                    #(#field_inits,)*
+                })
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn expand(src: &str) -> syn::Result<String> {
+        let input: DeriveInput = syn::parse_str(src)?;
+        expand_derive_from_row(input).map(|tokens| tokens.to_string())
+    }
+
+    #[test]
+    fn expands_a_plain_struct() {
+        let expanded = expand(
+            r#"
+            struct Person {
+                id: i32,
+                name: String,
+            }
+            "#,
+        )
+        .unwrap();
+
+        assert!(expanded.contains("impl tokio_postgres :: row :: FromRow for Person"));
+        assert!(expanded.contains("id : row . try_get (\"id\") ?"));
+        assert!(expanded.contains("name : row . try_get (\"name\") ?"));
+    }
+
+    #[test]
+    fn honors_a_rename_attribute() {
+        let expanded = expand(
+            r#"
+            struct Person {
+                #[postgres(rename = "full_name")]
+                name: String,
+            }
+            "#,
+        )
+        .unwrap();
+
+        assert!(expanded.contains("name : row . try_get (\"full_name\") ?"));
+    }
+
+    #[test]
+    fn honors_a_flatten_attribute() {
+        let expanded = expand(
+            r#"
+            struct Person {
+                #[postgres(flatten)]
+                address: Address,
+            }
+            "#,
+        )
+        .unwrap();
+
+        assert!(expanded
+            .contains("address : tokio_postgres :: row :: FromRow :: from_row (row) ?"));
+    }
+
+    #[test]
+    fn rejects_a_tuple_struct() {
+        let err = expand("struct Point(i32, i32);").unwrap_err();
+        assert!(err.to_string().contains("named fields"));
+    }
+
+    #[test]
+    fn rejects_a_non_struct() {
+        let err = expand("enum Shape { Circle, Square }").unwrap_err();
+        assert!(err.to_string().contains("can only be applied to structs"));
+    }
+}
@@ -0,0 +1,23 @@
+//! An internal crate for `tokio-postgres`'s `#[derive(FromRow)]`.
+
+#![recursion_limit = "256"]
+
+extern crate proc_macro;
+
+mod from_row;
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
+
+/// Derives an implementation of `tokio_postgres::row::FromRow`.
+///
+/// Each field is populated by calling `row.try_get("field_name")`. Use `#[postgres(rename =
+/// "...")]` to read from a differently-named column, and `#[postgres(flatten)]` to populate a
+/// field from a nested type that itself implements `FromRow`.
+#[proc_macro_derive(FromRow, attributes(postgres))]
+pub fn derive_from_row(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    from_row::expand_derive_from_row(input)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
@@ -0,0 +1,264 @@
+//! Conversions from Postgres' text wire format.
+
+use crate::types::Type;
+use std::error::Error as StdError;
+
+type Result<T> = std::result::Result<T, Box<dyn StdError + Sync + Send>>;
+
+/// A trait for types that can be created from a Postgres value encoded in the text format.
+///
+/// This mirrors [`crate::types::FromSql`], but decodes the human-readable text representation
+/// Postgres sends for a column whose result format is [`ProtocolEncodingFormat::Text`]
+/// (`crate::types::ProtocolEncodingFormat`) rather than the binary wire format.
+pub trait FromSqlText<'a>: Sized {
+    /// Converts a Postgres text value into a Rust value.
+    fn from_sql_text(ty: &Type, raw: &'a str) -> Result<Self>;
+
+    /// Determines if a value of this type can be created from the specified Postgres `NULL`.
+    ///
+    /// The default implementation returns `Err`, so only types that support a `NULL` value need
+    /// to override it (see the implementation for `Option<T>` below).
+    fn from_sql_text_null(ty: &Type) -> Result<Self> {
+        Err(format!("invalid NULL for non-nullable column of type {}", ty).into())
+    }
+
+    /// A convenience function that delegates to `from_sql_text` and `from_sql_text_null` depending
+    /// on the value of `raw`.
+    fn from_sql_text_nullable(ty: &Type, raw: Option<&'a str>) -> Result<Self> {
+        match raw {
+            Some(raw) => Self::from_sql_text(ty, raw),
+            None => Self::from_sql_text_null(ty),
+        }
+    }
+
+    /// Determines if a value of this type can be created from the specified Postgres `Type`.
+    fn accepts(ty: &Type) -> bool;
+}
+
+impl<'a, T> FromSqlText<'a> for Option<T>
+where
+    T: FromSqlText<'a>,
+{
+    fn from_sql_text(ty: &Type, raw: &'a str) -> Result<Self> {
+        T::from_sql_text(ty, raw).map(Some)
+    }
+
+    fn from_sql_text_null(_: &Type) -> Result<Self> {
+        Ok(None)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        T::accepts(ty)
+    }
+}
+
+macro_rules! simple_from_sql_text {
+    ($t:ty, $($ty:ident),+) => {
+        impl<'a> FromSqlText<'a> for $t {
+            fn from_sql_text(_: &Type, raw: &'a str) -> Result<Self> {
+                raw.parse().map_err(Into::into)
+            }
+
+            fn accepts(ty: &Type) -> bool {
+                matches!(*ty, $(Type::$ty)|+)
+            }
+        }
+    };
+}
+
+simple_from_sql_text!(i16, INT2);
+simple_from_sql_text!(i32, INT4);
+simple_from_sql_text!(i64, INT8);
+simple_from_sql_text!(f32, FLOAT4);
+simple_from_sql_text!(f64, FLOAT8);
+
+impl<'a> FromSqlText<'a> for bool {
+    fn from_sql_text(_: &Type, raw: &'a str) -> Result<Self> {
+        match raw {
+            "t" => Ok(true),
+            "f" => Ok(false),
+            _ => Err(format!("invalid boolean text value {:?}", raw).into()),
+        }
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        matches!(*ty, Type::BOOL)
+    }
+}
+
+impl<'a> FromSqlText<'a> for String {
+    fn from_sql_text(_: &Type, raw: &'a str) -> Result<Self> {
+        Ok(raw.to_string())
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        matches!(*ty, Type::TEXT | Type::VARCHAR | Type::BPCHAR | Type::NAME | Type::NUMERIC)
+    }
+}
+
+impl<'a> FromSqlText<'a> for &'a str {
+    fn from_sql_text(_: &Type, raw: &'a str) -> Result<Self> {
+        Ok(raw)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        <String as FromSqlText<'a>>::accepts(ty)
+    }
+}
+
+/// A naive Gregorian-calendar timestamp, parsed from Postgres' `YYYY-MM-DD HH:MM:SS[.ffffff]`
+/// text output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TextTimestamp {
+    /// The date component.
+    pub date: TextDate,
+    /// The hour, 0-23.
+    pub hour: u32,
+    /// The minute, 0-59.
+    pub minute: u32,
+    /// The second, 0-59.
+    pub second: u32,
+    /// The fractional second, in microseconds.
+    pub microsecond: u32,
+}
+
+/// A Gregorian calendar date, parsed from Postgres' `YYYY-MM-DD` text output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TextDate {
+    /// The year.
+    pub year: i32,
+    /// The month, 1-12.
+    pub month: u32,
+    /// The day of the month, 1-31.
+    pub day: u32,
+}
+
+fn parse_date(raw: &str) -> Result<TextDate> {
+    let mut parts = raw.splitn(3, '-');
+    let (year, month, day) = match (parts.next(), parts.next(), parts.next()) {
+        (Some(year), Some(month), Some(day)) => (year, month, day),
+        _ => return Err(format!("invalid date text value {:?}", raw).into()),
+    };
+
+    Ok(TextDate {
+        year: year.parse()?,
+        month: month.parse()?,
+        day: day.parse()?,
+    })
+}
+
+impl<'a> FromSqlText<'a> for TextDate {
+    fn from_sql_text(_: &Type, raw: &'a str) -> Result<Self> {
+        parse_date(raw)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        matches!(*ty, Type::DATE)
+    }
+}
+
+impl<'a> FromSqlText<'a> for TextTimestamp {
+    fn from_sql_text(_: &Type, raw: &'a str) -> Result<Self> {
+        let (date, time) = raw
+            .split_once(' ')
+            .ok_or_else(|| format!("invalid timestamp text value {:?}", raw))?;
+        let date = parse_date(date)?;
+
+        let (time, fraction) = match time.split_once('.') {
+            Some((time, fraction)) => (time, fraction),
+            None => (time, ""),
+        };
+
+        let mut parts = time.splitn(3, ':');
+        let (hour, minute, second) = match (parts.next(), parts.next(), parts.next()) {
+            (Some(hour), Some(minute), Some(second)) => (hour, minute, second),
+            _ => return Err(format!("invalid timestamp text value {:?}", raw).into()),
+        };
+
+        // Postgres prints between 0 and 6 fractional digits; pad to microseconds.
+        let mut microsecond = format!("{:0<6}", fraction);
+        microsecond.truncate(6);
+
+        Ok(TextTimestamp {
+            date,
+            hour: hour.parse()?,
+            minute: minute.parse()?,
+            second: second.parse()?,
+            microsecond: microsecond.parse()?,
+        })
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        matches!(*ty, Type::TIMESTAMP)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parses_integers_and_floats() {
+        assert_eq!(i32::from_sql_text(&Type::INT4, "-42").unwrap(), -42);
+        assert_eq!(i64::from_sql_text(&Type::INT8, "9000000000").unwrap(), 9000000000);
+        assert_eq!(f64::from_sql_text(&Type::FLOAT8, "3.5").unwrap(), 3.5);
+    }
+
+    #[test]
+    fn rejects_a_malformed_integer() {
+        assert!(i32::from_sql_text(&Type::INT4, "not a number").is_err());
+    }
+
+    #[test]
+    fn parses_bool_from_single_letter_text() {
+        assert!(bool::from_sql_text(&Type::BOOL, "t").unwrap());
+        assert!(!bool::from_sql_text(&Type::BOOL, "f").unwrap());
+        assert!(bool::from_sql_text(&Type::BOOL, "true").is_err());
+    }
+
+    #[test]
+    fn nullable_dispatches_on_none() {
+        assert_eq!(i32::from_sql_text_nullable(&Type::INT4, Some("7")).unwrap(), 7);
+        assert!(i32::from_sql_text_nullable(&Type::INT4, None).is_err());
+        assert_eq!(
+            Option::<i32>::from_sql_text_nullable(&Type::INT4, None).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn parses_a_date() {
+        let date = TextDate::from_sql_text(&Type::DATE, "2024-03-07").unwrap();
+        assert_eq!(
+            date,
+            TextDate {
+                year: 2024,
+                month: 3,
+                day: 7,
+            }
+        );
+    }
+
+    #[test]
+    fn parses_a_timestamp_without_a_fractional_second() {
+        let ts = TextTimestamp::from_sql_text(&Type::TIMESTAMP, "2024-03-07 13:45:01").unwrap();
+        assert_eq!(ts.hour, 13);
+        assert_eq!(ts.minute, 45);
+        assert_eq!(ts.second, 1);
+        assert_eq!(ts.microsecond, 0);
+    }
+
+    #[test]
+    fn pads_a_short_fractional_second_to_microseconds() {
+        // Postgres may print anywhere from 1 to 6 fractional digits; ".5" means 500000us, not 5us.
+        let ts = TextTimestamp::from_sql_text(&Type::TIMESTAMP, "2024-03-07 13:45:01.5").unwrap();
+        assert_eq!(ts.microsecond, 500_000);
+    }
+
+    #[test]
+    fn keeps_a_full_six_digit_fractional_second() {
+        let ts =
+            TextTimestamp::from_sql_text(&Type::TIMESTAMP, "2024-03-07 13:45:01.123456").unwrap();
+        assert_eq!(ts.microsecond, 123_456);
+    }
+}
@@ -0,0 +1,440 @@
+//! Named (`:name` / `@name`) query placeholders.
+//!
+//! [`query_named`]/[`execute_named`] are the end-to-end entry points: they rewrite the
+//! placeholders into Postgres' positional `$1..$n` syntax, prepare the rewritten query, reorder
+//! the caller's named arguments to match via [`bind_named_params`], and run it.
+
+use crate::types::ToSql;
+use crate::{Client, Error, Row};
+
+/// Rewrites `sql`, replacing each `:name` or `@name` placeholder with a positional `$k`
+/// placeholder, and returns the parameter names in first-appearance order: `names[i]` is bound to
+/// `$(offset + i + 1)`, where `offset` is the highest pre-existing native `$n` placeholder found
+/// in `sql` (`0` if there are none). Numbering named placeholders after any native ones keeps a
+/// query that mixes both styles (e.g. `... WHERE a = $1 AND b = :name`) from colliding.
+///
+/// A name that occurs more than once is assigned the same `$k` every time, so a caller using
+/// [`params_by_name!`] only needs to supply one value per distinct name; pass the returned names
+/// to [`bind_named_params`] to reorder the caller's arguments to match.
+///
+/// Placeholders are only recognized in "code" context. Single-quoted string literals,
+/// double-quoted identifiers, dollar-quoted strings (`$tag$ ... $tag$`), and `--`/`/* */`
+/// comments (including nested block comments) are copied through verbatim, and a `::` type-cast
+/// is never mistaken for a placeholder.
+pub(crate) fn rewrite_named_params(sql: &str) -> (String, Vec<String>) {
+    let chars: Vec<char> = sql.chars().collect();
+    let offset = max_native_placeholder(&chars);
+    let mut out = String::with_capacity(sql.len());
+    let mut names: Vec<String> = vec![];
+    let mut i = 0;
+
+    while i < chars.len() {
+        match chars[i] {
+            '\'' => i = copy_quoted(&chars, &mut out, i, '\''),
+            '"' => i = copy_quoted(&chars, &mut out, i, '"'),
+            '-' if chars.get(i + 1) == Some(&'-') => i = copy_line_comment(&chars, &mut out, i),
+            '/' if chars.get(i + 1) == Some(&'*') => i = copy_block_comment(&chars, &mut out, i),
+            '$' if dollar_quote_tag_end(&chars, i).is_some() => {
+                i = copy_dollar_quoted(&chars, &mut out, i)
+            }
+            ':' if chars.get(i + 1) == Some(&':') => {
+                out.push_str("::");
+                i += 2;
+            }
+            ':' | '@' if is_ident_start(chars.get(i + 1).copied()) => {
+                let (name, end) = read_ident(&chars, i + 1);
+                let k = match names.iter().position(|n| *n == name) {
+                    Some(pos) => pos,
+                    None => {
+                        names.push(name);
+                        names.len() - 1
+                    }
+                };
+                out.push('$');
+                out.push_str(&(offset + k + 1).to_string());
+                i = end;
+            }
+            c => {
+                out.push(c);
+                i += 1;
+            }
+        }
+    }
+
+    (out, names)
+}
+
+/// A named parameter and its value, built by [`params_by_name!`] and consumed by
+/// [`bind_named_params`].
+pub struct NamedParam<'a> {
+    name: &'a str,
+    value: &'a (dyn ToSql + Sync),
+}
+
+impl<'a> NamedParam<'a> {
+    /// Pairs a parameter name with its value.
+    pub fn new(name: &'a str, value: &'a (dyn ToSql + Sync)) -> Self {
+        NamedParam { name, value }
+    }
+}
+
+/// Builds a `&[NamedParam]` for use with [`bind_named_params`].
+///
+/// ```ignore
+/// let params = params_by_name!["id" => &id, "name" => &name];
+/// ```
+#[macro_export]
+macro_rules! params_by_name {
+    ($($name:expr => $value:expr),* $(,)?) => {
+        &[$($crate::named_params::NamedParam::new($name, $value)),*][..]
+    };
+}
+
+/// Reorders `params` (supplied by name, e.g. via [`params_by_name!`]) to match `names` — the
+/// parameter-name order returned alongside a rewritten statement when preparing a query with
+/// named placeholders — producing the positional argument slice `$1..$n` expects.
+///
+/// Returns an error if `names` contains a name that isn't present in `params`.
+pub fn bind_named_params<'a>(
+    names: &[String],
+    params: &[NamedParam<'a>],
+) -> Result<Vec<&'a (dyn ToSql + Sync)>, Error> {
+    names
+        .iter()
+        .map(|name| {
+            params
+                .iter()
+                .find(|p| p.name == name)
+                .map(|p| p.value)
+                .ok_or_else(|| Error::column(format!("no value provided for parameter `{}`", name)))
+        })
+        .collect()
+}
+
+/// Runs `query` (rewriting any `:name`/`@name` placeholders to positional `$k`s) with `params`
+/// supplied by name, returning the resulting rows.
+///
+/// This is the end-to-end entry point for named placeholders: `query` is rewritten and prepared
+/// via [`Client::prepare`], `params` is reordered into positional order via
+/// [`bind_named_params`], and the prepared statement is then run via [`Client::query`].
+///
+/// ```ignore
+/// let rows = query_named(
+///     &client,
+///     "SELECT * FROM people WHERE id = :id",
+///     params_by_name!["id" => &id],
+/// )
+/// .await?;
+/// ```
+pub async fn query_named(
+    client: &Client,
+    query: &str,
+    params: &[NamedParam<'_>],
+) -> Result<Vec<Row>, Error> {
+    let (rewritten, names) = rewrite_named_params(query);
+    let statement = client.prepare(&rewritten).await?;
+    let args = bind_named_params(&names, params)?;
+    client.query(&statement, &args).await
+}
+
+/// Like [`query_named`], but for statements that don't return rows (`INSERT`, `UPDATE`, ...),
+/// returning the number of rows affected.
+pub async fn execute_named(
+    client: &Client,
+    query: &str,
+    params: &[NamedParam<'_>],
+) -> Result<u64, Error> {
+    let (rewritten, names) = rewrite_named_params(query);
+    let statement = client.prepare(&rewritten).await?;
+    let args = bind_named_params(&names, params)?;
+    client.execute(&statement, &args).await
+}
+
+/// Returns the highest `$n` native placeholder number appearing in `sql` (in code context),
+/// or `0` if there are none.
+fn max_native_placeholder(chars: &[char]) -> usize {
+    // Reuse the same quote/comment-skipping logic as the rewriter, discarding its output; only
+    // the final index each helper returns is needed here.
+    let mut scratch = String::new();
+    let mut max = 0;
+    let mut i = 0;
+
+    while i < chars.len() {
+        match chars[i] {
+            '\'' => i = copy_quoted(chars, &mut scratch, i, '\''),
+            '"' => i = copy_quoted(chars, &mut scratch, i, '"'),
+            '-' if chars.get(i + 1) == Some(&'-') => i = copy_line_comment(chars, &mut scratch, i),
+            '/' if chars.get(i + 1) == Some(&'*') => i = copy_block_comment(chars, &mut scratch, i),
+            '$' if dollar_quote_tag_end(chars, i).is_some() => {
+                i = copy_dollar_quoted(chars, &mut scratch, i)
+            }
+            '$' if chars.get(i + 1).map_or(false, |c| c.is_ascii_digit()) => {
+                let (digits, end) = read_digits(chars, i + 1);
+                if let Ok(n) = digits.parse::<usize>() {
+                    max = max.max(n);
+                }
+                i = end;
+            }
+            _ => i += 1,
+        }
+    }
+
+    max
+}
+
+/// Reads a run of ASCII digits starting at `start`, returning them along with the index just
+/// past them.
+fn read_digits(chars: &[char], start: usize) -> (String, usize) {
+    let mut end = start;
+    while end < chars.len() && chars[end].is_ascii_digit() {
+        end += 1;
+    }
+    (chars[start..end].iter().collect(), end)
+}
+
+fn is_ident_start(c: Option<char>) -> bool {
+    matches!(c, Some(c) if c.is_ascii_alphabetic() || c == '_')
+}
+
+fn is_ident_continue(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
+
+/// Reads an identifier starting at `start`, returning it along with the index just past it.
+fn read_ident(chars: &[char], start: usize) -> (String, usize) {
+    let mut end = start;
+    while end < chars.len() && is_ident_continue(chars[end]) {
+        end += 1;
+    }
+    (chars[start..end].iter().collect(), end)
+}
+
+/// Copies a `'...'` or `"..."` literal (with `quote` doubled as the escape for an embedded
+/// `quote`) verbatim, returning the index just past its closing quote.
+fn copy_quoted(chars: &[char], out: &mut String, start: usize, quote: char) -> usize {
+    out.push(quote);
+    let mut i = start + 1;
+    while i < chars.len() {
+        if chars[i] == quote {
+            if chars.get(i + 1) == Some(&quote) {
+                out.push(quote);
+                out.push(quote);
+                i += 2;
+                continue;
+            }
+            out.push(quote);
+            return i + 1;
+        }
+        out.push(chars[i]);
+        i += 1;
+    }
+    i
+}
+
+/// Copies a `-- ...` comment up to (but not including) the terminating newline, if any.
+fn copy_line_comment(chars: &[char], out: &mut String, start: usize) -> usize {
+    let mut i = start;
+    while i < chars.len() && chars[i] != '\n' {
+        out.push(chars[i]);
+        i += 1;
+    }
+    i
+}
+
+/// Copies a `/* ... */` comment, accounting for Postgres' support for nested block comments.
+fn copy_block_comment(chars: &[char], out: &mut String, start: usize) -> usize {
+    out.push_str("/*");
+    let mut i = start + 2;
+    let mut depth = 1;
+    while i < chars.len() && depth > 0 {
+        if chars[i] == '/' && chars.get(i + 1) == Some(&'*') {
+            out.push_str("/*");
+            depth += 1;
+            i += 2;
+        } else if chars[i] == '*' && chars.get(i + 1) == Some(&'/') {
+            out.push_str("*/");
+            depth -= 1;
+            i += 2;
+        } else {
+            out.push(chars[i]);
+            i += 1;
+        }
+    }
+    i
+}
+
+/// If `chars[start]` opens a valid dollar-quoted tag (`$tag$`, tag following identifier rules, or
+/// empty), returns the index of the `$` that closes the opening delimiter.
+fn dollar_quote_tag_end(chars: &[char], start: usize) -> Option<usize> {
+    let mut i = start + 1;
+    if chars.get(i) == Some(&'$') {
+        return Some(i);
+    }
+    if !is_ident_start(chars.get(i).copied()) {
+        return None;
+    }
+    while i < chars.len() && is_ident_continue(chars[i]) {
+        i += 1;
+    }
+    if chars.get(i) == Some(&'$') {
+        Some(i)
+    } else {
+        None
+    }
+}
+
+/// Copies a `$tag$ ... $tag$` dollar-quoted string verbatim, returning the index just past its
+/// closing delimiter (or the end of input if it's unterminated).
+fn copy_dollar_quoted(chars: &[char], out: &mut String, start: usize) -> usize {
+    let open_end = dollar_quote_tag_end(chars, start).unwrap();
+    let tag: Vec<char> = chars[start + 1..open_end].to_vec();
+
+    let mut j = open_end + 1;
+    let close = loop {
+        if j >= chars.len() {
+            break None;
+        }
+        if chars[j] == '$' {
+            let end = j + 1 + tag.len();
+            if end < chars.len() && chars[j + 1..end] == tag[..] && chars[end] == '$' {
+                break Some(end);
+            }
+        }
+        j += 1;
+    };
+
+    match close {
+        Some(close_dollar) => {
+            out.extend(&chars[start..=close_dollar]);
+            close_dollar + 1
+        }
+        None => {
+            out.extend(&chars[start..]);
+            chars.len()
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn rewrites_single_named_param() {
+        let (sql, names) = rewrite_named_params("SELECT * FROM people WHERE id = :id");
+        assert_eq!(sql, "SELECT * FROM people WHERE id = $1");
+        assert_eq!(names, vec!["id"]);
+    }
+
+    #[test]
+    fn reuses_the_same_k_for_a_repeated_name() {
+        let (sql, names) = rewrite_named_params("WHERE a = :x OR b = :x OR c = @y");
+        assert_eq!(sql, "WHERE a = $1 OR b = $1 OR c = $2");
+        assert_eq!(names, vec!["x", "y"]);
+    }
+
+    #[test]
+    fn skips_single_quoted_string_literals() {
+        let (sql, names) = rewrite_named_params("SELECT ':name', :real_name");
+        assert_eq!(sql, "SELECT ':name', $1");
+        assert_eq!(names, vec!["real_name"]);
+    }
+
+    #[test]
+    fn handles_an_escaped_quote_inside_a_string_literal() {
+        let (sql, names) = rewrite_named_params("SELECT 'it''s :fine' WHERE id = :id");
+        assert_eq!(sql, "SELECT 'it''s :fine' WHERE id = $1");
+        assert_eq!(names, vec!["id"]);
+    }
+
+    #[test]
+    fn skips_double_quoted_identifiers() {
+        let (sql, names) = rewrite_named_params(r#"SELECT "col:name" FROM t WHERE id = :id"#);
+        assert_eq!(sql, r#"SELECT "col:name" FROM t WHERE id = $1"#);
+        assert_eq!(names, vec!["id"]);
+    }
+
+    #[test]
+    fn skips_dollar_quoted_strings() {
+        let (sql, names) =
+            rewrite_named_params("SELECT $tag$contains :not_a_param$tag$ WHERE id = :id");
+        assert_eq!(
+            sql,
+            "SELECT $tag$contains :not_a_param$tag$ WHERE id = $1"
+        );
+        assert_eq!(names, vec!["id"]);
+    }
+
+    #[test]
+    fn skips_empty_dollar_quoted_strings() {
+        let (sql, names) = rewrite_named_params("SELECT $$:not_a_param$$ WHERE id = :id");
+        assert_eq!(sql, "SELECT $$:not_a_param$$ WHERE id = $1");
+        assert_eq!(names, vec!["id"]);
+    }
+
+    #[test]
+    fn skips_line_comments() {
+        let (sql, names) = rewrite_named_params("SELECT 1 -- :not_a_param\nWHERE id = :id");
+        assert_eq!(sql, "SELECT 1 -- :not_a_param\nWHERE id = $1");
+        assert_eq!(names, vec!["id"]);
+    }
+
+    #[test]
+    fn skips_nested_block_comments() {
+        let (sql, names) =
+            rewrite_named_params("SELECT 1 /* outer /* inner :not_a_param */ still-comment */ WHERE id = :id");
+        assert_eq!(
+            sql,
+            "SELECT 1 /* outer /* inner :not_a_param */ still-comment */ WHERE id = $1"
+        );
+        assert_eq!(names, vec!["id"]);
+    }
+
+    #[test]
+    fn does_not_mistake_a_type_cast_for_a_placeholder() {
+        let (sql, names) = rewrite_named_params("SELECT value::text WHERE id = :id");
+        assert_eq!(sql, "SELECT value::text WHERE id = $1");
+        assert_eq!(names, vec!["id"]);
+    }
+
+    #[test]
+    fn numbers_named_params_after_existing_native_placeholders() {
+        let (sql, names) = rewrite_named_params("WHERE a = $1 AND b = :name");
+        assert_eq!(sql, "WHERE a = $1 AND b = $2");
+        assert_eq!(names, vec!["name"]);
+    }
+
+    #[test]
+    fn numbers_named_params_after_the_highest_native_placeholder_even_if_it_appears_later() {
+        let (sql, names) = rewrite_named_params("WHERE b = :name AND a = $3");
+        assert_eq!(sql, "WHERE b = $4 AND a = $3");
+        assert_eq!(names, vec!["name"]);
+    }
+
+    #[test]
+    fn bind_named_params_reorders_by_name() {
+        let id = 7i32;
+        let name = "alice".to_string();
+        let params = params_by_name!["name" => &name, "id" => &id];
+
+        let bound = bind_named_params(&["id".to_string(), "name".to_string()], params).unwrap();
+        assert_eq!(bound.len(), 2);
+        assert!(std::ptr::eq(
+            bound[0] as *const (dyn ToSql + Sync) as *const u8,
+            &id as *const i32 as *const u8
+        ));
+        assert!(std::ptr::eq(
+            bound[1] as *const (dyn ToSql + Sync) as *const u8,
+            &name as *const String as *const u8
+        ));
+    }
+
+    #[test]
+    fn bind_named_params_errors_on_an_unsupplied_name() {
+        let id = 7i32;
+        let params = params_by_name!["id" => &id];
+
+        let err = bind_named_params(&["id".to_string(), "name".to_string()], params);
+        assert!(err.is_err());
+    }
+}
@@ -0,0 +1,36 @@
+//! Fallible mapping adapters over query results.
+//
+// Both adapters are thin wrappers around an existing `Stream`/`FallibleIterator` combinator with
+// the caller's own closure plugged in, so there's no crate-internal branching to cover beyond what
+// `futures_util`/`fallible_iterator` already test themselves.
+
+use crate::{Error, Row, RowStream};
+use fallible_iterator::FallibleIterator;
+use futures_util::{future, stream::Stream, TryStreamExt};
+
+/// Maps a `RowStream` into a `Stream` of `T`, applying `f` to each row as it arrives.
+///
+/// Unlike collecting into a `Vec<Row>` first, `f` runs per row as the stream yields it, so a
+/// failure reported by `f` (for example a `Row::try_get` error) surfaces as the next item of the
+/// returned stream rather than as a panic.
+pub fn query_map<F, T, E>(stream: RowStream, mut f: F) -> impl Stream<Item = Result<T, E>>
+where
+    F: FnMut(&Row) -> Result<T, E>,
+    E: From<Error>,
+{
+    stream
+        .map_err(E::from)
+        .and_then(move |row| future::ready(f(&row)))
+}
+
+/// Maps an iterator of `Row`s (for example a `Vec<Row>`) into a `FallibleIterator` of `T`.
+///
+/// Reach for this when the rows are already collected (e.g. a sync `Vec<Row>` result) and all
+/// that's left is applying `f`; use [`query_map`] instead to map a `RowStream` as it streams.
+pub fn query_and_then<I, F, T, E>(rows: I, mut f: F) -> impl FallibleIterator<Item = T, Error = E>
+where
+    I: IntoIterator<Item = Row>,
+    F: FnMut(&Row) -> Result<T, E>,
+{
+    fallible_iterator::convert(rows.into_iter().map(Ok::<Row, E>)).and_then(move |row| f(&row))
+}
@@ -1,11 +1,13 @@
 //! Rows.
 
+use crate::from_sql_text::FromSqlText;
 use crate::row::sealed::{AsName, Sealed};
 use crate::simple_query::SimpleColumn;
 use crate::statement::Column;
 use crate::types::{FromSql, Type, WrongType};
-use crate::{Error, Statement};
+use crate::{Error, RowStream, Statement};
 use fallible_iterator::FallibleIterator;
+use futures_util::{pin_mut, TryStreamExt};
 use postgres_protocol::message::backend::DataRowBody;
 use std::fmt;
 use std::ops::Range;
@@ -154,9 +156,15 @@ impl Row {
     ///
     /// The value can be specified either by its numeric index in the row, or by its column name.
     ///
+    /// This only decodes binary-format rows; a text-format row's value can't be widened into a
+    /// `T: FromSql` at the type level, since most `FromSql` implementors (`Uuid`, `serde_json::Value`,
+    /// `chrono` types, ...) have no corresponding `FromSqlText` impl. Use [`Row::get_text`] for a
+    /// row returned in the text format instead of calling this.
+    ///
     /// # Panics
     ///
-    /// Panics if the index is out of bounds or if the value cannot be converted to the specified type.
+    /// Panics if the index is out of bounds, the row is text-format, or the value cannot be
+    /// converted to the specified type.
     pub fn get<'a, I, T>(&'a self, idx: I) -> T
     where
         I: RowIndex + fmt::Display,
@@ -182,24 +190,95 @@ impl Row {
         I: RowIndex + fmt::Display,
         T: FromSql<'a>,
     {
+        let idx = match idx.__idx(self.columns()) {
+            Some(idx) => idx,
+            None => return Err(Error::column(idx.to_string())),
+        };
+
         if !self.extract_allowed {
-            self.fail_non_binary_format()?;
+            return Err(self.fail_non_binary_format(idx));
+        }
+
+        let ty = self.columns()[idx].type_();
+        if !T::accepts(ty) {
+            return Err(Error::from_sql(
+                Box::new(WrongType::new::<T>(ty.clone())),
+                idx,
+            ));
         }
 
+        FromSql::from_sql_nullable(ty, self.col_buffer(idx)).map_err(|e| Error::from_sql(e, idx))
+    }
+
+    /// Deserializes a value from a text-format column of the row.
+    ///
+    /// Unlike [`Row::get`], which requires the row to have been returned in the binary format,
+    /// this decodes Postgres' text wire representation via [`FromSqlText`]. Use it for a row
+    /// whose statement was prepared with a text result format.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds, the row isn't text-format, or the value cannot be
+    /// converted to the specified type.
+    pub fn get_text<'a, I, T>(&'a self, idx: I) -> T
+    where
+        I: RowIndex + fmt::Display,
+        T: FromSqlText<'a>,
+    {
+        match self.get_inner_text(&idx) {
+            Ok(ok) => ok,
+            Err(err) => panic!("error retrieving column {}: {}", idx, err),
+        }
+    }
+
+    /// Like `Row::get_text`, but returns a `Result` rather than panicking.
+    pub fn try_get_text<'a, I, T>(&'a self, idx: I) -> Result<T, Error>
+    where
+        I: RowIndex + fmt::Display,
+        T: FromSqlText<'a>,
+    {
+        self.get_inner_text(&idx)
+    }
+
+    fn get_inner_text<'a, I, T>(&'a self, idx: &I) -> Result<T, Error>
+    where
+        I: RowIndex + fmt::Display,
+        T: FromSqlText<'a>,
+    {
         let idx = match idx.__idx(self.columns()) {
             Some(idx) => idx,
             None => return Err(Error::column(idx.to_string())),
         };
 
+        if self.extract_allowed {
+            return Err(Error::column(format!(
+                "column {} must be text-format to use get_text/try_get_text",
+                idx
+            )));
+        }
+
         let ty = self.columns()[idx].type_();
-        if !T::accepts(ty) {
+        if !<T as FromSqlText>::accepts(ty) {
             return Err(Error::from_sql(
                 Box::new(WrongType::new::<T>(ty.clone())),
                 idx,
             ));
         }
 
-        FromSql::from_sql_nullable(ty, self.col_buffer(idx)).map_err(|e| Error::from_sql(e, idx))
+        let raw = match self.col_buffer(idx) {
+            Some(buf) => Some(str::from_utf8(buf).map_err(|e| Error::from_sql(Box::new(e), idx))?),
+            None => None,
+        };
+
+        FromSqlText::from_sql_text_nullable(ty, raw).map_err(|e| Error::from_sql(e, idx))
+    }
+
+    #[cold]
+    fn fail_non_binary_format(&self, idx: usize) -> Error {
+        Error::column(format!(
+            "column {} must be binary-format to use get/try_get; use get_text/try_get_text instead",
+            idx
+        ))
     }
 
     /// Get the raw bytes for the column at the given index.
@@ -208,16 +287,10 @@ impl Row {
         Some(&self.body.buffer()[range])
     }
 
-    #[cold]
-    fn fail_non_binary_format(&self) -> Result<(), Error> {
-        return Err(Error::column(format!(
-            "format must be binary to support parameter extraction"
-        )));
-    }
-
-    /// return true if parameters ca be extracted with 'get' 
-    /// and 'try_get'. This is only possible if encoding is
-    /// 'Binary' for each column
+    /// Returns true if every column in the row was returned in the binary format.
+    ///
+    /// `get`/`try_get` require the row to be binary; a text-format row needs
+    /// [`Row::get_text`]/[`Row::try_get_text`] instead.
     pub fn extract_allowed(&self) -> bool {
         self.extract_allowed
     }
@@ -303,3 +376,64 @@ impl SimpleQueryRow {
         FromSql::from_sql_nullable(&Type::TEXT, buf).map_err(|e| Error::from_sql(e, idx))
     }
 }
+
+/// A trait for types that can be created from a `Row`.
+///
+/// `#[derive(FromRow)]` (from the `tokio-postgres-derive` crate) implements this for a struct by
+/// calling `row.try_get(name)` for each field. It can also be implemented by hand for types that
+/// need custom mapping logic.
+// The derive's expansion is covered by tests in `tokio-postgres-derive`; a hand-written `FromRow`
+// impl is just caller code with no crate-internal logic to exercise here.
+pub trait FromRow: Sized {
+    /// Performs the conversion.
+    fn from_row(row: &Row) -> Result<Self, Error>;
+}
+
+macro_rules! impl_from_row_for_tuple {
+    ($($idx:tt => $T:ident),+) => {
+        impl<$($T),+> FromRow for ($($T,)+)
+        where
+            $($T: for<'a> FromSql<'a>),+
+        {
+            fn from_row(row: &Row) -> Result<Self, Error> {
+                Ok(($(row.try_get($idx)?,)+))
+            }
+        }
+    };
+}
+
+impl_from_row_for_tuple!(0 => T0);
+impl_from_row_for_tuple!(0 => T0, 1 => T1);
+impl_from_row_for_tuple!(0 => T0, 1 => T1, 2 => T2);
+impl_from_row_for_tuple!(0 => T0, 1 => T1, 2 => T2, 3 => T3);
+impl_from_row_for_tuple!(0 => T0, 1 => T1, 2 => T2, 3 => T3, 4 => T4);
+impl_from_row_for_tuple!(0 => T0, 1 => T1, 2 => T2, 3 => T3, 4 => T4, 5 => T5);
+impl_from_row_for_tuple!(0 => T0, 1 => T1, 2 => T2, 3 => T3, 4 => T4, 5 => T5, 6 => T6);
+impl_from_row_for_tuple!(0 => T0, 1 => T1, 2 => T2, 3 => T3, 4 => T4, 5 => T5, 6 => T6, 7 => T7);
+
+/// Collects the rows of a `RowStream` into a `Vec` of `T`, converting each row with `FromRow`.
+///
+/// This is the `query_as`-style counterpart to [`crate::Client::query`] for callers who want a
+/// typed result instead of raw `Row`s.
+pub async fn query_as<T>(stream: RowStream) -> Result<Vec<T>, Error>
+where
+    T: FromRow,
+{
+    pin_mut!(stream);
+    let mut out = vec![];
+    while let Some(row) = stream.try_next().await? {
+        out.push(T::from_row(&row)?);
+    }
+    Ok(out)
+}
+
+/// Converts a `Vec<Row>` into a `Vec` of `T`, converting each row with `FromRow`.
+///
+/// Unlike [`query_as`], this doesn't need an executor to await — reach for it when a `Vec<Row>`
+/// is already on hand (e.g. from [`crate::Client::query`]) and all that's left is the mapping.
+pub fn rows_as<T>(rows: Vec<Row>) -> Result<Vec<T>, Error>
+where
+    T: FromRow,
+{
+    rows.iter().map(T::from_row).collect()
+}